@@ -1,18 +1,20 @@
-use algebra::Field;
+use algebra::{CanonicalDeserialize, CanonicalSerialize, Field, ToConstraintField};
 pub use ff_fft::DensePolynomial as Polynomial;
 use rand_core::RngCore;
 use std::borrow::Cow;
 
 /// Defines the minimal interface for public params for any polynomial
 /// commitment scheme.
-pub trait PCUniversalParams: Clone + std::fmt::Debug {
+pub trait PCUniversalParams:
+    Clone + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize
+{
     /// Outputs the maximum degree supported by the committer key.
     fn max_degree(&self) -> usize;
 }
 
 /// Defines the minimal interface of committer keys for any polynomial
 /// commitment scheme.
-pub trait PCCommitterKey: Clone + std::fmt::Debug {
+pub trait PCCommitterKey: Clone + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize {
     /// Outputs the maximum degree supported by the universal parameters
     /// `Self` was derived from.
     fn max_degree(&self) -> usize;
@@ -23,7 +25,9 @@ pub trait PCCommitterKey: Clone + std::fmt::Debug {
 
 /// Defines the minimal interface of verifier keys for any polynomial
 /// commitment scheme.
-pub trait PCVerifierKey: Clone + std::fmt::Debug {
+pub trait PCVerifierKey:
+    Clone + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize + Prepare
+{
     /// Outputs the maximum degree supported by the universal parameters
     /// `Self` was derived from.
     fn max_degree(&self) -> usize;
@@ -32,17 +36,55 @@ pub trait PCVerifierKey: Clone + std::fmt::Debug {
     fn supported_degree(&self) -> usize;
 }
 
+/// Defines the minimal interface for preparing a data structure into an
+/// amortized form that precomputes work shared by many subsequent operations
+/// — e.g. the `G2` line coefficients / Miller-loop-ready form a pairing-based
+/// verifier key only needs to compute once, not on every verification.
+///
+/// Schemes with nothing to amortize (e.g. purely transparent ones) can simply
+/// set `Prepared = Self`.
+pub trait Prepare {
+    /// The prepared form of `Self`.
+    type Prepared: Clone;
+
+    /// Precompute the prepared form of `self`.
+    fn prepare(&self) -> Self::Prepared;
+}
+
+/// Emits the minimal boolean decomposition of a value's group elements, so
+/// that it can be absorbed into an in-circuit Fiat-Shamir sponge. This is
+/// typically a tighter encoding than `ToConstraintField`, since it does not
+/// need to round up to a full field element per group coordinate.
+pub trait ToMinimalBits {
+    /// Returns the minimal bit representation of `self`.
+    fn to_minimal_bits(&self) -> Vec<bool>;
+}
+
+fn u64_to_bits_le(value: u64, num_bits: usize) -> Vec<bool> {
+    (0..num_bits).map(|i| (value >> i) & 1 == 1).collect()
+}
+
 /// Defines the minimal interface of commitments for any polynomial
 /// commitment scheme.
-pub trait PCCommitment: Clone + algebra::ToBytes {
+///
+/// Schemes that are meant to be verified inside a circuit (e.g. as part of a
+/// recursive SNARK) should additionally implement `ToConstraintField<CF>`
+/// for the constraint field `CF` of the outer circuit, so that a commitment
+/// can be absorbed as constraint-field elements by an in-circuit verifier.
+pub trait PCCommitment:
+    Clone + algebra::ToBytes + CanonicalSerialize + CanonicalDeserialize + Prepare + ToMinimalBits
+{
     /// Outputs a non-hiding commitment to the zero polynomial.
     fn empty() -> Self;
 
     /// Does this commitment have a degree bound?
     fn has_degree_bound(&self) -> bool;
 
-    /// Size in bytes
-    fn size_in_bytes(&self) -> usize;
+    /// Size in bytes. Equivalent to `CanonicalSerialize::serialized_size`;
+    /// this name predates the `CanonicalSerialize` bound above.
+    fn size_in_bytes(&self) -> usize {
+        self.serialized_size()
+    }
 }
 
 /// Defines the minimal interface of commitment randomness for any polynomial
@@ -58,9 +100,12 @@ pub trait PCRandomness: Clone {
 
 /// Defines the minimal interface of evaluation proofs for any polynomial
 /// commitment scheme.
-pub trait PCProof: Clone + algebra::ToBytes {
-    /// Size in bytes
-    fn size_in_bytes(&self) -> usize;
+pub trait PCProof: Clone + algebra::ToBytes + CanonicalSerialize + CanonicalDeserialize {
+    /// Size in bytes. Equivalent to `CanonicalSerialize::serialized_size`;
+    /// this name predates the `CanonicalSerialize` bound above.
+    fn size_in_bytes(&self) -> usize {
+        self.serialized_size()
+    }
 }
 
 /// A polynomial along with information about its degree bound (if any), and the
@@ -144,6 +189,149 @@ impl<'a, F: Field> LabeledPolynomial<'a, F> {
     }
 }
 
+/// A polynomial in two variables `x` and `y`, represented as its "row"
+/// polynomials in `y`: `f(x, y) = Σ_i x^i · row_polynomials[i](y)`. Used to
+/// support distributed key generation and verifiable secret sharing, where a
+/// dealer commits to a symmetric `f(x, y) = f(y, x)` and each party `i`
+/// receives (and must verify) the univariate share `f(i, ·)`.
+#[derive(Debug, Clone)]
+pub struct BivariatePolynomial<F: Field> {
+    row_polynomials: Vec<Polynomial<F>>,
+}
+
+impl<F: Field> BivariatePolynomial<F> {
+    /// Construct a bivariate polynomial from its row polynomials, i.e. the
+    /// coefficient of `x^i` is `row_polynomials[i](y)`.
+    pub fn from_row_polynomials(row_polynomials: Vec<Polynomial<F>>) -> Self {
+        Self { row_polynomials }
+    }
+
+    /// The row polynomials `f_i(y)`, the coefficient of `x^i`.
+    pub fn row_polynomials(&self) -> &[Polynomial<F>] {
+        &self.row_polynomials
+    }
+
+    /// The degree of `f` in `x`.
+    pub fn degree_in_x(&self) -> usize {
+        self.row_polynomials.len().saturating_sub(1)
+    }
+
+    /// The degree of `f` in `y`, i.e. the largest degree among its rows.
+    pub fn degree_in_y(&self) -> usize {
+        self.row_polynomials
+            .iter()
+            .map(|row| row.degree())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Evaluate `f(x, y)` at the given point.
+    pub fn evaluate(&self, x: F, y: F) -> F {
+        let mut result = F::zero();
+        let mut x_pow = F::one();
+        for row in &self.row_polynomials {
+            result += row.evaluate(y) * x_pow;
+            x_pow *= x;
+        }
+        result
+    }
+
+    /// Extract the univariate row polynomial `f(x, ·) = Σ_i x^i · f_i(y)`
+    /// obtained by fixing the first variable to `x`. This is what a dealer
+    /// running a DKG sends to party `x` as its share.
+    pub fn row_at(&self, x: F) -> Polynomial<F> {
+        let mut coeffs = vec![F::zero(); self.degree_in_y() + 1];
+        let mut x_pow = F::one();
+        for row in &self.row_polynomials {
+            for (c, rc) in coeffs.iter_mut().zip(&row.coeffs) {
+                *c += *rc * x_pow;
+            }
+            x_pow *= x;
+        }
+        Polynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+/// A bivariate polynomial along with information about its degree bound (if
+/// any), and the maximum number of queries it will be opened at. Mirrors
+/// `LabeledPolynomial`, but over `BivariatePolynomial` instead of
+/// `Polynomial`.
+#[derive(Debug, Clone)]
+pub struct LabeledBivariatePolynomial<'a, F: Field> {
+    label: String,
+    polynomial: Cow<'a, BivariatePolynomial<F>>,
+    degree_bound: Option<usize>,
+    hiding_bound: Option<usize>,
+}
+
+impl<'a, F: Field> LabeledBivariatePolynomial<'a, F> {
+    /// Construct a new labeled bivariate polynomial by consuming `polynomial`.
+    pub fn new_owned(
+        label: String,
+        polynomial: BivariatePolynomial<F>,
+        degree_bound: Option<usize>,
+        hiding_bound: Option<usize>,
+    ) -> Self {
+        Self {
+            label,
+            polynomial: Cow::Owned(polynomial),
+            degree_bound,
+            hiding_bound,
+        }
+    }
+
+    /// Construct a new labeled bivariate polynomial.
+    pub fn new(
+        label: String,
+        polynomial: &'a BivariatePolynomial<F>,
+        degree_bound: Option<usize>,
+        hiding_bound: Option<usize>,
+    ) -> Self {
+        Self {
+            label,
+            polynomial: Cow::Borrowed(polynomial),
+            degree_bound,
+            hiding_bound,
+        }
+    }
+
+    /// Return the label for `self`.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Retrieve the polynomial from `self`.
+    pub fn polynomial(&self) -> &BivariatePolynomial<F> {
+        &self.polynomial
+    }
+
+    /// Evaluate `f(x, y)` in `self`.
+    pub fn evaluate(&self, x: F, y: F) -> F {
+        self.polynomial.evaluate(x, y)
+    }
+
+    /// Extract the univariate row polynomial `f(x, ·)` in `self`, i.e. the
+    /// share that would be sent to party `x`.
+    pub fn row_at(&self, x: F) -> Polynomial<F> {
+        self.polynomial.row_at(x)
+    }
+
+    /// Retrieve the degree bound in `self`.
+    pub fn degree_bound(&self) -> Option<usize> {
+        self.degree_bound
+    }
+
+    /// Retrieve whether the polynomial in `self` should be hidden.
+    pub fn is_hiding(&self) -> bool {
+        self.hiding_bound.is_some()
+    }
+
+    /// Retrieve the hiding bound for the polynomial in `self`.
+    pub fn hiding_bound(&self) -> Option<usize> {
+        self.hiding_bound
+    }
+}
+
 /// A commitment along with information about its degree bound (if any).
 #[derive(Clone)]
 pub struct LabeledCommitment<C: PCCommitment> {
@@ -184,3 +372,118 @@ impl<C: PCCommitment> algebra::ToBytes for LabeledCommitment<C> {
         self.commitment.write(writer)
     }
 }
+
+impl<C: PCCommitment> CanonicalSerialize for LabeledCommitment<C> {
+    fn serialize<W: std::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<(), algebra::SerializationError> {
+        self.label.as_bytes().to_vec().serialize(&mut writer)?;
+        self.degree_bound.serialize(&mut writer)?;
+        self.commitment.serialize(&mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.label.as_bytes().to_vec().serialized_size()
+            + self.degree_bound.serialized_size()
+            + self.commitment.serialized_size()
+    }
+}
+
+impl<C: PCCommitment> CanonicalDeserialize for LabeledCommitment<C> {
+    fn deserialize<R: std::io::Read>(mut reader: R) -> Result<Self, algebra::SerializationError> {
+        let label_bytes: Vec<u8> = CanonicalDeserialize::deserialize(&mut reader)?;
+        let label = String::from_utf8(label_bytes).map_err(|_| algebra::SerializationError::InvalidData)?;
+        let degree_bound = CanonicalDeserialize::deserialize(&mut reader)?;
+        let commitment = C::deserialize(&mut reader)?;
+        Ok(Self {
+            label,
+            commitment,
+            degree_bound,
+        })
+    }
+}
+
+/// A prepared commitment along with information about its degree bound (if
+/// any). Mirrors `LabeledCommitment`, but stores the amortized form produced
+/// by `PCCommitment::prepare` so that a verifier checking many proofs against
+/// the same commitment does not redo the preparation work each time.
+#[derive(Clone)]
+pub struct LabeledPreparedCommitment<C: PCCommitment> {
+    label: String,
+    prepared_commitment: C::Prepared,
+    degree_bound: Option<usize>,
+}
+
+impl<C: PCCommitment> LabeledPreparedCommitment<C> {
+    /// Instantiate a new labeled prepared commitment.
+    pub fn new(label: String, prepared_commitment: C::Prepared, degree_bound: Option<usize>) -> Self {
+        Self {
+            label,
+            prepared_commitment,
+            degree_bound,
+        }
+    }
+
+    /// Return the label for `self`.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Retrieve the prepared commitment from `self`.
+    pub fn prepared_commitment(&self) -> &C::Prepared {
+        &self.prepared_commitment
+    }
+
+    /// Retrieve the degree bound in `self`.
+    pub fn degree_bound(&self) -> Option<usize> {
+        self.degree_bound
+    }
+}
+
+impl<C: PCCommitment> ToMinimalBits for LabeledCommitment<C> {
+    /// Packs the underlying commitment's bits together with the label's
+    /// length and bytes and the degree bound, in that deterministic order,
+    /// so that two labeled commitments differing only in metadata never
+    /// collide once absorbed into an in-circuit sponge. The length is
+    /// absorbed before the label's own bytes so that labels of different
+    /// lengths can never be confused with each other regardless of content.
+    fn to_minimal_bits(&self) -> Vec<bool> {
+        let mut bits = self.commitment.to_minimal_bits();
+        bits.extend(u64_to_bits_le(self.label.len() as u64, 64));
+        for byte in self.label.as_bytes() {
+            bits.extend(u64_to_bits_le(*byte as u64, 8));
+        }
+        bits.push(self.degree_bound.is_some());
+        bits.extend(u64_to_bits_le(self.degree_bound.unwrap_or(0) as u64, 64));
+        bits
+    }
+}
+
+impl<C: PCCommitment + ToConstraintField<CF>, CF: Field> ToConstraintField<CF>
+    for LabeledCommitment<C>
+{
+    /// Packs the underlying commitment's field elements together with the
+    /// label's length, the label's own bytes (one field element per byte),
+    /// and the degree bound, in that deterministic order, mirroring
+    /// `ToMinimalBits::to_minimal_bits`.
+    fn to_field_elements(&self) -> Option<Vec<CF>> {
+        let mut fes = self.commitment.to_field_elements()?;
+        fes.push(CF::from(self.label.len() as u64));
+        fes.extend(self.label.as_bytes().iter().map(|byte| CF::from(*byte as u64)));
+        fes.push(CF::from(self.degree_bound.is_some() as u64));
+        fes.push(CF::from(self.degree_bound.unwrap_or(0) as u64));
+        Some(fes)
+    }
+}
+
+impl<C: PCCommitment> From<&LabeledCommitment<C>> for LabeledPreparedCommitment<C> {
+    /// Prepare `comm`, retaining its label and degree bound.
+    fn from(comm: &LabeledCommitment<C>) -> Self {
+        Self::new(
+            comm.label().to_string(),
+            comm.commitment().prepare(),
+            comm.degree_bound(),
+        )
+    }
+}
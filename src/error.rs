@@ -0,0 +1,71 @@
+use core::fmt;
+
+/// The error type for `PolynomialCommitment`.
+#[derive(Debug)]
+pub enum Error {
+    /// The degree provided in setup was too small; degree 0 polynomials
+    /// are not supported.
+    DegreeIsZero,
+
+    /// The degree of the polynomial passed to `commit` or `open` was too
+    /// large for the committer key.
+    TooManyCoefficients {
+        /// The number of coefficients in the offending polynomial.
+        num_coefficients: usize,
+        /// The maximum number of coefficients the committer key supports.
+        num_powers: usize,
+    },
+
+    /// A degree bound was requested that is unsupported by the committer or
+    /// verifier key.
+    UnsupportedDegreeBound(usize),
+
+    /// An evaluation was missing for a commitment that `batch_check` was
+    /// asked to verify.
+    MissingEvaluation {
+        /// The label of the commitment with the missing evaluation.
+        label: String,
+    },
+
+    /// An input was rejected as malformed: the lengths of two or more inputs
+    /// that were expected to match did not, or an input requested something
+    /// the scheme does not support (e.g. a hiding commitment in `ipa_pc`).
+    IncorrectInputLength(String),
+
+    /// The computation was aborted because its terminator flag was set.
+    Terminated,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DegreeIsZero => write!(
+                f,
+                "this scheme does not support committing to degree 0 polynomials"
+            ),
+            Error::TooManyCoefficients {
+                num_coefficients,
+                num_powers,
+            } => write!(
+                f,
+                "the number of coefficients in the polynomial ({:?}) is greater than\
+                 the maximum number of powers in the committer key ({:?})",
+                num_coefficients, num_powers
+            ),
+            Error::UnsupportedDegreeBound(bound) => write!(
+                f,
+                "the degree bound ({:?}) is not supported by the parameters",
+                bound
+            ),
+            Error::MissingEvaluation { label } => write!(
+                f,
+                "an evaluation for the polynomial labeled `{}` was expected but not supplied",
+                label
+            ),
+            Error::IncorrectInputLength(err) => write!(f, "{}", err),
+            Error::Terminated => write!(f, "terminated before completion"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,129 @@
+//! An accumulation scheme for `InnerProductArgPC`: instead of paying for a
+//! full multi-scalar multiplication on every opening proof it checks, a
+//! verifier runs only the succinct (`O(log d)`) part of each proof's check
+//! and folds the result into a running `Accumulator`, deferring every
+//! proof's `O(d)`-sized generator reconstruction into a single combined MSM
+//! that `Accumulator::verify` pays for once, no matter how many proofs were
+//! accumulated. Deferring the expensive part of verification this way is
+//! what makes proof-carrying-data-style recursion practical: an intermediate
+//! verifier in the recursion only has to run the cheap, succinct part and
+//! can pass the accumulator along instead.
+use super::{fold_point_powers, fold_weights, Commitment, InnerProductArgPC, Proof, VerifierKey};
+use crate::Error;
+use algebra::{AffineCurve, Field, ProjectiveCurve};
+use digest::Digest;
+
+/// The result of succinctly checking one opening proof: everything needed to
+/// later confirm the proof was valid, short of the expensive final MSM.
+struct AccumulatorInstance<G: AffineCurve> {
+    /// The `(u, u_inv)` challenge of each fold round, in round order.
+    challenges: Vec<(G::ScalarField, G::ScalarField)>,
+    /// The cheaply (`O(log d)`) folded accumulator `P' = P + Σ u_i⁻¹·L_i +
+    /// u_i·R_i`.
+    accumulator: G::Projective,
+    /// `fold_point_powers(point, challenges)`, i.e. the `b` the fully folded
+    /// generator vector would have folded down to, computed in `O(log d)`.
+    final_b: G::ScalarField,
+    c: G::ScalarField,
+}
+
+/// Accumulates opening proofs so their expensive final check can be batched
+/// into a single multi-scalar multiplication.
+pub struct Accumulator<G: AffineCurve> {
+    s: Option<G>,
+    comm_key: Option<Vec<G>>,
+    instances: Vec<AccumulatorInstance<G>>,
+}
+
+impl<G: AffineCurve> Accumulator<G> {
+    /// Starts an empty accumulator.
+    pub fn new() -> Self {
+        Self {
+            s: None,
+            comm_key: None,
+            instances: Vec::new(),
+        }
+    }
+
+    /// Runs the cheap, succinct part of checking `proof` — everything except
+    /// the final MSM — and folds the result into `self`. All proofs
+    /// accumulated together must share the same verifier key.
+    pub fn accumulate<D: Digest>(
+        &mut self,
+        vk: &VerifierKey<G>,
+        commitment: &Commitment<G>,
+        point: G::ScalarField,
+        value: G::ScalarField,
+        proof: &Proof<G>,
+    ) -> Result<(), Error> {
+        let accumulator = commitment.0.into_projective() + &vk.s.mul(value);
+        let (challenges, accumulator) = InnerProductArgPC::<G, D>::succinct_verify(
+            vk.comm_key.len(),
+            point,
+            accumulator,
+            proof,
+        )?;
+        let final_b = fold_point_powers(point, &challenges);
+
+        self.s.get_or_insert(vk.s);
+        self.comm_key.get_or_insert_with(|| vk.comm_key.clone());
+        self.instances.push(AccumulatorInstance {
+            challenges,
+            accumulator,
+            final_b,
+            c: proof.c,
+        });
+        Ok(())
+    }
+
+    /// Checks every accumulated instance at once. Each instance's relation is
+    /// `accumulator_i == G_final_i^{c_i} · s^{c_i·final_b_i}`, where
+    /// `G_final_i = Σ_j fold_weights(challenges_i)[j]·comm_key[j]` is the
+    /// `O(d)`-sized generator reconstruction `accumulate` deferred.
+    /// Combining every instance's `G_final_i` reconstruction into one weight
+    /// vector over `comm_key` (scaled by successive powers of a challenge
+    /// derived from the instances) turns what would be `N` separate `O(d)`
+    /// MSMs into a single `O(d)` MSM, no matter how many proofs were
+    /// accumulated.
+    pub fn verify<D: Digest>(&self) -> bool {
+        let (s, comm_key) = match (self.s, &self.comm_key) {
+            (Some(s), Some(comm_key)) => (s, comm_key),
+            _ => return true,
+        };
+
+        let mut transcript = Vec::new();
+        for instance in &self.instances {
+            super::append_to_transcript(&mut transcript, &instance.accumulator.into_affine());
+        }
+        let challenge: G::ScalarField = super::squeeze_challenge::<D, _>(&transcript);
+
+        let mut power = G::ScalarField::one();
+        let mut lhs = G::Projective::zero();
+        let mut generator_weights = vec![G::ScalarField::zero(); comm_key.len()];
+        let mut s_scalar = G::ScalarField::zero();
+
+        for instance in &self.instances {
+            lhs += &instance.accumulator.mul(power);
+
+            let coeff = instance.c * power;
+            for (weight, fold_weight) in generator_weights
+                .iter_mut()
+                .zip(fold_weights(&instance.challenges))
+            {
+                *weight += &(coeff * fold_weight);
+            }
+
+            s_scalar += &(coeff * instance.final_b);
+            power *= &challenge;
+        }
+
+        let rhs = super::pedersen_commit::<G>(comm_key, &generator_weights) + &s.mul(s_scalar);
+        lhs == rhs
+    }
+}
+
+impl<G: AffineCurve> Default for Accumulator<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
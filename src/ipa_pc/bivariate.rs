@@ -0,0 +1,81 @@
+//! Pedersen commitments to bivariate polynomials, for distributed key
+//! generation and verifiable secret sharing: a dealer commits to a symmetric
+//! `f(x, y)` and each party `i` can check its row share `f(i, ·)` against the
+//! published commitment without learning `f` itself.
+use super::pedersen_commit;
+use crate::{BivariatePolynomial, Error, Polynomial};
+use algebra::{AffineCurve, Field, ProjectiveCurve};
+
+/// Checks that `generators` has enough entries to commit to `num_coefficients`
+/// coefficients, returning the matching error the univariate path uses
+/// instead of panicking on an out-of-range slice.
+fn check_enough_generators<G: AffineCurve>(
+    generators: &[G],
+    num_coefficients: usize,
+) -> Result<(), Error> {
+    if num_coefficients > generators.len() {
+        Err(Error::TooManyCoefficients {
+            num_coefficients,
+            num_powers: generators.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Commits to a bivariate polynomial by Pedersen-committing each of its row
+/// polynomials independently, in the same order as
+/// `BivariatePolynomial::row_polynomials`.
+#[derive(Clone, Debug)]
+pub struct BivariateCommitment<G: AffineCurve> {
+    /// `row_commitments[i]` commits to `f_i(y)`, the row polynomial
+    /// multiplying `x^i`.
+    pub row_commitments: Vec<G>,
+}
+
+impl<G: AffineCurve> BivariateCommitment<G> {
+    /// Commits to every row of `polynomial` under `comm_key`.
+    pub fn commit(
+        comm_key: &[G],
+        polynomial: &BivariatePolynomial<G::ScalarField>,
+    ) -> Result<Self, Error> {
+        let row_commitments = polynomial
+            .row_polynomials()
+            .iter()
+            .map(|row| {
+                check_enough_generators(comm_key, row.coeffs.len())?;
+                Ok(pedersen_commit::<G>(&comm_key[..row.coeffs.len()], &row.coeffs).into_affine())
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Self { row_commitments })
+    }
+
+    /// Checks that `share`, claimed to be the row polynomial `f(x, ·)` at the
+    /// given `x`, is consistent with `self`, by recombining the row
+    /// commitments the same way `BivariatePolynomial::row_at` recombines the
+    /// row polynomials themselves:
+    ///
+    /// `Commit(f(x, ·)) == Σ_i x^i · row_commitments[i]`.
+    ///
+    /// This lets any party holding only its own share, not the whole
+    /// polynomial, verify that the dealer sent it a value consistent with
+    /// the one published commitment.
+    pub fn verify_share(
+        &self,
+        comm_key: &[G],
+        x: G::ScalarField,
+        share: &Polynomial<G::ScalarField>,
+    ) -> Result<bool, Error> {
+        check_enough_generators(comm_key, share.coeffs.len())?;
+
+        let mut expected = G::Projective::zero();
+        let mut x_pow = G::ScalarField::one();
+        for row_commitment in &self.row_commitments {
+            expected += &row_commitment.mul(x_pow);
+            x_pow *= x;
+        }
+
+        let actual = pedersen_commit::<G>(&comm_key[..share.coeffs.len()], &share.coeffs);
+        Ok(actual == expected)
+    }
+}
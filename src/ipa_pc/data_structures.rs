@@ -0,0 +1,168 @@
+use crate::{PCCommitterKey, PCRandomness, PCUniversalParams, PCVerifierKey, Prepare, ToMinimalBits};
+use algebra::{AffineCurve, CanonicalDeserialize, CanonicalSerialize, ToBytes, UniformRand};
+use rand_core::RngCore;
+
+/// `UniversalParams` for the discrete-log (Pedersen/IPA) scheme: a public,
+/// uniformly sampled vector of group generators. Unlike the pairing-based
+/// schemes in this crate, these parameters do not come from a trusted setup
+/// — any party can reproduce them from public randomness.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct UniversalParams<G: AffineCurve> {
+    /// The generators `[g_0, ..., g_d]` used to Pedersen-commit to a
+    /// polynomial's coefficients.
+    pub comm_key: Vec<G>,
+    /// An extra generator used only during opening: folding `a` against
+    /// `comm_key` and, in parallel, the claimed evaluation `⟨a, b⟩` against
+    /// `s`, is what lets the inner-product argument bind a `Commit(f)`
+    /// opening to a specific `f(point) = value`, not merely to `f`'s
+    /// coefficients.
+    pub s: G,
+}
+
+impl<G: AffineCurve> PCUniversalParams for UniversalParams<G> {
+    fn max_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+/// The committer key for the discrete-log scheme: the prefix of
+/// `UniversalParams::comm_key` supporting polynomials up to the degree the
+/// key was trimmed to (rounded up so the prefix length is a power of two).
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKey<G: AffineCurve> {
+    /// The generators used to commit to a polynomial's coefficients.
+    pub comm_key: Vec<G>,
+    /// The generator used to bind an opening to a claimed evaluation; see
+    /// `UniversalParams::s`.
+    pub s: G,
+    /// The maximum degree supported by the universal parameters this key
+    /// was trimmed from.
+    pub max_degree: usize,
+}
+
+impl<G: AffineCurve> PCCommitterKey for CommitterKey<G> {
+    fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    fn supported_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+/// The verifier key for the discrete-log scheme. Identical in shape to the
+/// committer key: verification needs the same generators the committer
+/// used, since there is no trusted setup splitting the two.
+pub type VerifierKey<G> = CommitterKey<G>;
+
+impl<G: AffineCurve> PCVerifierKey for VerifierKey<G> {
+    fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    fn supported_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+impl<G: AffineCurve> Prepare for CommitterKey<G> {
+    type Prepared = Self;
+
+    /// There is nothing to amortize for a transparent, discrete-log-based
+    /// verifier key, so preparation is the identity.
+    fn prepare(&self) -> Self::Prepared {
+        self.clone()
+    }
+}
+
+/// A Pedersen/inner-product commitment: `Commit(f) = Σ a_i·g_i`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<G: AffineCurve>(pub G);
+
+impl<G: AffineCurve> ToBytes for Commitment<G> {
+    fn write<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<G: AffineCurve> Prepare for Commitment<G> {
+    type Prepared = Self;
+
+    /// A Pedersen commitment is a single group element; there is no
+    /// Miller-loop-style precomputation to amortize, so preparation is the
+    /// identity.
+    fn prepare(&self) -> Self::Prepared {
+        *self
+    }
+}
+
+impl<G: AffineCurve> ToMinimalBits for Commitment<G> {
+    fn to_minimal_bits(&self) -> Vec<bool> {
+        let mut bytes = Vec::new();
+        self.0
+            .write(&mut bytes)
+            .expect("writing to a Vec<u8> never fails");
+        bytes
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect()
+    }
+}
+
+impl<G: AffineCurve> crate::PCCommitment for Commitment<G> {
+    fn empty() -> Self {
+        Self(G::zero())
+    }
+
+    /// This initial discrete-log backend does not yet support degree-bounded
+    /// openings (only plain commit/open and accumulation, per its original
+    /// scope); every commitment reports no degree bound.
+    fn has_degree_bound(&self) -> bool {
+        false
+    }
+}
+
+/// Commitment randomness. This backend is non-hiding: commitments are never
+/// blinded, and `commit_with_terminator` always returns `Randomness::empty()`.
+#[derive(Clone, Debug)]
+pub struct Randomness<G: AffineCurve>(pub G::ScalarField);
+
+impl<G: AffineCurve> PCRandomness for Randomness<G> {
+    fn empty() -> Self {
+        Self(G::ScalarField::from(0u64))
+    }
+
+    fn rand<R: RngCore>(_num_queries: usize, rng: &mut R) -> Self {
+        Self(G::ScalarField::rand(rng))
+    }
+}
+
+/// An opening proof for the logarithmic inner-product argument: the `L`/`R`
+/// vectors produced by each folding round, the final folded generator, and
+/// the final folded scalar.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<G: AffineCurve> {
+    /// `L_i = ⟨a_lo, G_hi⟩` for each round `i`.
+    pub l_vec: Vec<G>,
+    /// `R_i = ⟨a_hi, G_lo⟩` for each round `i`.
+    pub r_vec: Vec<G>,
+    /// The single generator the commitment key folds down to.
+    pub final_comm_key: G,
+    /// The single scalar the coefficient vector folds down to.
+    pub c: G::ScalarField,
+}
+
+impl<G: AffineCurve> ToBytes for Proof<G> {
+    fn write<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        for l in &self.l_vec {
+            l.write(&mut writer)?;
+        }
+        for r in &self.r_vec {
+            r.write(&mut writer)?;
+        }
+        self.final_comm_key.write(&mut writer)?;
+        self.c.write(&mut writer)
+    }
+}
+
+impl<G: AffineCurve> crate::PCProof for Proof<G> {}
@@ -0,0 +1,638 @@
+//! A transparent, pairing-free polynomial commitment scheme built from a
+//! Pedersen/inner-product commitment and a logarithmic inner-product
+//! argument (IPA), in the style of Bulletproofs/Halo. No trusted setup is
+//! required: the commitment key is a vector of generators that anyone can
+//! reproduce from public randomness.
+//!
+//! This backend is intentionally non-hiding: `Commit(f) = Σ a_i·g_i`, with
+//! no blinding term, and `commit_with_terminator` rejects any polynomial
+//! with a hiding bound. Opening proves the committed coefficients directly,
+//! so a blinded commitment could never be opened again; supporting hiding
+//! would mean threading a blinding factor through the inner-product
+//! argument itself, which this scheme does not do.
+use crate::{
+    check_terminator, Error, LabeledCommitment, LabeledPolynomial, PCRandomness, PolynomialCommitment,
+};
+use algebra::{
+    AffineCurve, CanonicalSerialize, Field, PrimeField, ProjectiveCurve, ToBytes, UniformRand,
+};
+use digest::Digest;
+use rand_core::RngCore;
+use std::marker::PhantomData;
+use std::sync::atomic::AtomicBool;
+
+mod data_structures;
+pub use data_structures::*;
+
+pub mod accumulation;
+pub mod bivariate;
+
+/// The `[1, z, z^2, ..., z^{n-1}]` vector the inner-product argument folds
+/// against the coefficient vector.
+fn point_powers<F: Field>(point: F, len: usize) -> Vec<F> {
+    let mut powers = Vec::with_capacity(len);
+    let mut cur = F::one();
+    for _ in 0..len {
+        powers.push(cur);
+        cur *= point;
+    }
+    powers
+}
+
+/// `Σ s_i·g_i`, the Pedersen commitment of `scalars` under `generators`.
+fn pedersen_commit<G: AffineCurve>(generators: &[G], scalars: &[G::ScalarField]) -> G::Projective {
+    let mut acc = G::Projective::zero();
+    for (g, s) in generators.iter().zip(scalars) {
+        acc += &g.mul(*s);
+    }
+    acc
+}
+
+/// The number of generator/scalar multiplications `pedersen_commit_chunked`
+/// processes before re-checking `terminator`.
+const MSM_CHUNK_SIZE: usize = 1 << 10;
+
+/// Same as `pedersen_commit`, but checks `terminator` every `MSM_CHUNK_SIZE`
+/// terms, so an abort request is honored while committing to a single large
+/// polynomial, not only in between polynomials.
+fn pedersen_commit_chunked<G: AffineCurve>(
+    generators: &[G],
+    scalars: &[G::ScalarField],
+    terminator: &AtomicBool,
+) -> Result<G::Projective, Error> {
+    let mut acc = G::Projective::zero();
+    for (chunk_generators, chunk_scalars) in generators
+        .chunks(MSM_CHUNK_SIZE)
+        .zip(scalars.chunks(MSM_CHUNK_SIZE))
+    {
+        check_terminator(terminator)?;
+        for (g, s) in chunk_generators.iter().zip(chunk_scalars) {
+            acc += &g.mul(*s);
+        }
+    }
+    Ok(acc)
+}
+
+/// The plain inner product `⟨a, b⟩ = Σ a_i·b_i`.
+fn inner_product<F: Field>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b).map(|(x, y)| *x * y).sum()
+}
+
+fn append_to_transcript<G: AffineCurve>(transcript: &mut Vec<u8>, point: &G) {
+    point
+        .write(transcript)
+        .expect("writing to a Vec<u8> never fails");
+}
+
+/// Starts a transcript bound to the statement being proven: the combined
+/// commitment/claimed-value accumulator `P = Commit(f) + value·s` and the
+/// evaluation `point`. Both `open` and `verifier_fold` must seed their
+/// transcript with this before deriving the first round challenge, so that
+/// every challenge depends on what is being proven and not only on the
+/// proof's own `L`/`R` values (otherwise a prover could grind `L`/`R`/`c`
+/// against a challenge schedule fixed independently of the statement).
+fn seed_transcript<G: AffineCurve>(point: G::ScalarField, accumulator: G::Projective) -> Vec<u8> {
+    let mut transcript = Vec::new();
+    append_to_transcript(&mut transcript, &accumulator.into_affine());
+    point
+        .serialize(&mut transcript)
+        .expect("writing to a Vec<u8> never fails");
+    transcript
+}
+
+/// Given the `(u, u_inv)` challenges of each fold round (in round order),
+/// returns the weight `fold_weights(..)[j]` that the `j`-th original,
+/// unfolded generator/power is multiplied by in the final folded value.
+/// Folding only ever multiplies the "hi" half of a round by `u_inv`, so
+/// working backwards from the last round, each step doubles the weight
+/// vector via `[w, w·u_inv]`; this reconstructs the same per-index weights
+/// a full fold would produce in `O(n)` instead of needing the `O(log n)`
+/// sequence of `O(n)`-sized folds. Letting the caller apply these weights to
+/// the *original* (unfolded) generators is what lets many proofs share a
+/// single combined MSM instead of each paying for its own fold.
+fn fold_weights<F: Field>(challenges: &[(F, F)]) -> Vec<F> {
+    let mut weights = vec![F::one()];
+    for (_, u_inv) in challenges.iter().rev() {
+        let mut next = Vec::with_capacity(weights.len() * 2);
+        next.extend_from_slice(&weights);
+        next.extend(weights.iter().map(|w| *w * u_inv));
+        weights = next;
+    }
+    weights
+}
+
+/// Computes the folded `b = [1, point, point^2, ...]` scalar directly from
+/// the round challenges, in `O(log n)` rather than the `O(n)` an explicit
+/// fold costs. Each round's "hi" half of a powers vector is its "lo" half
+/// scaled by `point` raised to the halfway length (`b_hi = point^{n/2}·
+/// b_lo`), so folding by `u_inv` telescopes into the product below instead
+/// of needing to materialize the vector.
+fn fold_point_powers<F: Field>(point: F, challenges: &[(F, F)]) -> F {
+    let log_n = challenges.len();
+    let mut powers_of_two = Vec::with_capacity(log_n);
+    let mut cur = point;
+    for _ in 0..log_n {
+        powers_of_two.push(cur);
+        cur *= cur;
+    }
+
+    let mut result = F::one();
+    for (round, (_, u_inv)) in challenges.iter().enumerate() {
+        let factor = powers_of_two[log_n - 1 - round];
+        let mut term = F::one();
+        term += &(*u_inv * factor);
+        result *= term;
+    }
+    result
+}
+
+/// Derives a Fiat-Shamir challenge from the transcript bytes accumulated so
+/// far, hashing with `D` and interpreting the digest as a field element.
+/// Re-hashes (with an incrementing counter appended) until the digest lands
+/// inside the field, so the resulting challenge distribution is uniform.
+fn squeeze_challenge<D: Digest, F: PrimeField>(transcript: &[u8]) -> F {
+    let mut counter: u64 = 0;
+    loop {
+        let mut hasher = D::new();
+        hasher.update(transcript);
+        hasher.update(&counter.to_le_bytes());
+        let digest = hasher.finalize();
+        if let Some(challenge) = F::from_random_bytes(&digest) {
+            return challenge;
+        }
+        counter += 1;
+    }
+}
+
+/// One folding round of the inner-product argument: `a`, `b`, and `comm_key`
+/// each halve in length. Holds the round's `(L, R)` pair, which
+/// `prover_round` has already appended to the transcript to derive `u`.
+struct FoldRound<G: AffineCurve> {
+    l: G::Projective,
+    r: G::Projective,
+}
+
+/// The discrete-log (Pedersen/IPA) polynomial commitment scheme over the
+/// group `G`, with Fiat-Shamir challenges derived via the hash function `D`.
+pub struct InnerProductArgPC<G: AffineCurve, D: Digest> {
+    _group: PhantomData<G>,
+    _digest: PhantomData<D>,
+}
+
+impl<G: AffineCurve, D: Digest> InnerProductArgPC<G, D> {
+    /// Runs one prover-side folding round, binding `L`/`R` to both the
+    /// coefficient/generator halves (as `Commit` does) and to the partial
+    /// inner products against `s` (so the argument also certifies `⟨a, b⟩`,
+    /// i.e. the claimed evaluation).
+    fn prover_round(
+        a: &mut Vec<G::ScalarField>,
+        b: &mut Vec<G::ScalarField>,
+        comm_key: &mut Vec<G>,
+        s: G,
+        transcript: &mut Vec<u8>,
+    ) -> FoldRound<G> {
+        let half = a.len() / 2;
+        let (a_lo, a_hi) = a.split_at(half);
+        let (b_lo, b_hi) = b.split_at(half);
+        let (g_lo, g_hi) = comm_key.split_at(half);
+
+        let l = pedersen_commit::<G>(g_hi, a_lo) + &s.mul(inner_product(a_lo, b_hi));
+        let r = pedersen_commit::<G>(g_lo, a_hi) + &s.mul(inner_product(a_hi, b_lo));
+
+        append_to_transcript(transcript, &l.into_affine());
+        append_to_transcript(transcript, &r.into_affine());
+        let u: G::ScalarField = squeeze_challenge::<D, _>(transcript);
+        let u_inv = u
+            .inverse()
+            .expect("a Fiat-Shamir challenge is zero only with negligible probability");
+
+        *a = a_lo.iter().zip(a_hi).map(|(lo, hi)| *lo + u * hi).collect();
+        *b = b_lo.iter().zip(b_hi).map(|(lo, hi)| *lo + u_inv * hi).collect();
+        *comm_key = g_lo
+            .iter()
+            .zip(g_hi)
+            .map(|(lo, hi)| (lo.into_projective() + &hi.mul(u_inv)).into_affine())
+            .collect();
+
+        FoldRound { l, r }
+    }
+
+    /// Folds the public generator vector and verifier-side accumulator using
+    /// challenges re-derived from `proof.l_vec`/`proof.r_vec`, mirroring
+    /// `prover_round` without needing `a`. Returns the folded generator, the
+    /// folded `b`, and the folded accumulator `P' = P + Σ u_i⁻¹·L_i + u_i·R_i`
+    /// (the cross terms pick up the *inverse* of the coefficient `a`/`b`/
+    /// `comm_key` themselves fold with, since `L`/`R` telescope the other
+    /// way).
+    fn verifier_fold(
+        mut comm_key: Vec<G>,
+        point: G::ScalarField,
+        mut accumulator: G::Projective,
+        proof: &Proof<G>,
+    ) -> Result<(G, G::ScalarField, G::Projective), Error> {
+        if proof.l_vec.len() != proof.r_vec.len() {
+            return Err(Error::IncorrectInputLength(
+                "mismatched L/R vector lengths in IPA proof".to_string(),
+            ));
+        }
+
+        let mut b = point_powers(point, comm_key.len());
+        let mut transcript = seed_transcript::<G>(point, accumulator);
+
+        for (l, r) in proof.l_vec.iter().zip(&proof.r_vec) {
+            append_to_transcript(&mut transcript, l);
+            append_to_transcript(&mut transcript, r);
+            let u: G::ScalarField = squeeze_challenge::<D, _>(&transcript);
+            let u_inv = u
+                .inverse()
+                .expect("a Fiat-Shamir challenge is zero only with negligible probability");
+
+            accumulator += &(l.mul(u_inv) + &r.mul(u));
+
+            let half = b.len() / 2;
+            let (b_lo, b_hi) = b.split_at(half);
+            b = b_lo.iter().zip(b_hi).map(|(lo, hi)| *lo + u_inv * hi).collect();
+
+            let (g_lo, g_hi) = comm_key.split_at(half);
+            comm_key = g_lo
+                .iter()
+                .zip(g_hi)
+                .map(|(lo, hi)| (lo.into_projective() + &hi.mul(u_inv)).into_affine())
+                .collect();
+        }
+
+        Ok((comm_key[0], b[0], accumulator))
+    }
+
+    /// The succinct part of verifying an opening proof: re-derives each
+    /// round's `(u, u_inv)` challenge from the transcript and cheaply folds
+    /// only the `O(log n)`-sized accumulator, *without* folding the
+    /// `O(n)`-sized generator or power vectors `verifier_fold` folds. A
+    /// caller that needs the fully folded generator/`b` (e.g. to combine many
+    /// proofs into one MSM) can reconstruct them afterwards via
+    /// `fold_weights`/`fold_point_powers`, applied once across every
+    /// accumulated proof instead of once per proof.
+    pub(crate) fn succinct_verify(
+        n: usize,
+        point: G::ScalarField,
+        mut accumulator: G::Projective,
+        proof: &Proof<G>,
+    ) -> Result<(Vec<(G::ScalarField, G::ScalarField)>, G::Projective), Error> {
+        if proof.l_vec.len() != proof.r_vec.len() {
+            return Err(Error::IncorrectInputLength(
+                "mismatched L/R vector lengths in IPA proof".to_string(),
+            ));
+        }
+        if 1usize << proof.l_vec.len() != n {
+            return Err(Error::IncorrectInputLength(format!(
+                "proof has {} fold rounds, expected {} for a degree-{} committer key",
+                proof.l_vec.len(),
+                n.trailing_zeros(),
+                n - 1
+            )));
+        }
+
+        let mut transcript = seed_transcript::<G>(point, accumulator);
+        let mut challenges = Vec::with_capacity(proof.l_vec.len());
+
+        for (l, r) in proof.l_vec.iter().zip(&proof.r_vec) {
+            append_to_transcript(&mut transcript, l);
+            append_to_transcript(&mut transcript, r);
+            let u: G::ScalarField = squeeze_challenge::<D, _>(&transcript);
+            let u_inv = u
+                .inverse()
+                .expect("a Fiat-Shamir challenge is zero only with negligible probability");
+
+            accumulator += &(l.mul(u_inv) + &r.mul(u));
+            challenges.push((u, u_inv));
+        }
+
+        Ok((challenges, accumulator))
+    }
+}
+
+impl<G: AffineCurve, D: Digest + 'static> PolynomialCommitment<G::ScalarField>
+    for InnerProductArgPC<G, D>
+{
+    type UniversalParams = UniversalParams<G>;
+    type CommitterKey = CommitterKey<G>;
+    type VerifierKey = VerifierKey<G>;
+    type Commitment = Commitment<G>;
+    type Randomness = Randomness<G>;
+    type Proof = Proof<G>;
+    type BatchProof = Vec<Self::Proof>;
+    type Error = Error;
+
+    fn setup<R: RngCore>(
+        max_degree: usize,
+        rng: &mut R,
+    ) -> Result<Self::UniversalParams, Self::Error> {
+        if max_degree == 0 {
+            return Err(Error::DegreeIsZero);
+        }
+        let degree_plus_one = (max_degree + 1).next_power_of_two();
+        let comm_key = (0..degree_plus_one)
+            .map(|_| G::Projective::rand(rng).into_affine())
+            .collect();
+        let s = G::Projective::rand(rng).into_affine();
+        Ok(UniversalParams { comm_key, s })
+    }
+
+    fn trim(
+        pp: &Self::UniversalParams,
+        supported_degree: usize,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), Self::Error> {
+        if supported_degree > pp.max_degree() {
+            return Err(Error::UnsupportedDegreeBound(supported_degree));
+        }
+        let degree_plus_one = (supported_degree + 1).next_power_of_two();
+        let ck = CommitterKey {
+            comm_key: pp.comm_key[..degree_plus_one].to_vec(),
+            s: pp.s,
+            max_degree: pp.max_degree(),
+        };
+        let vk = ck.clone();
+        Ok((ck, vk))
+    }
+
+    fn commit_with_terminator<'a>(
+        ck: &Self::CommitterKey,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<'a, G::ScalarField>>,
+        terminator: &AtomicBool,
+        _rng: Option<&mut dyn RngCore>,
+    ) -> Result<
+        (
+            Vec<LabeledCommitment<Self::Commitment>>,
+            Vec<Self::Randomness>,
+        ),
+        Self::Error,
+    > {
+        let mut comms = Vec::new();
+        let mut rands = Vec::new();
+
+        for labeled_polynomial in polynomials {
+            check_terminator(terminator)?;
+
+            // This backend is intentionally non-hiding: the inner-product
+            // argument opens the committed coefficients directly, with no
+            // blinding term, so a hiding commitment produced here could
+            // never be opened again. Reject it up front instead of silently
+            // handing back a proof that can't verify.
+            if labeled_polynomial.is_hiding() {
+                return Err(Error::IncorrectInputLength(format!(
+                    "the ipa_pc backend does not support hiding commitments; \
+                     polynomial `{}` has a hiding bound",
+                    labeled_polynomial.label()
+                )));
+            }
+
+            let coeffs = &labeled_polynomial.polynomial().coeffs;
+            if coeffs.len() > ck.comm_key.len() {
+                return Err(Error::TooManyCoefficients {
+                    num_coefficients: coeffs.len(),
+                    num_powers: ck.comm_key.len(),
+                });
+            }
+
+            let randomness = Randomness::empty();
+            let commitment =
+                pedersen_commit_chunked::<G>(&ck.comm_key[..coeffs.len()], coeffs, terminator)?;
+
+            comms.push(LabeledCommitment::new(
+                labeled_polynomial.label().to_string(),
+                Commitment(commitment.into_affine()),
+                labeled_polynomial.degree_bound(),
+            ));
+            rands.push(randomness);
+        }
+
+        Ok((comms, rands))
+    }
+
+    /// Opens one or more previously committed polynomials at `point`.
+    ///
+    /// Note: this backend is intentionally non-hiding (see the module-level
+    /// doc), so it only ever opens non-hiding polynomials; `rands` here is
+    /// always `Randomness::empty()`, since `commit_with_terminator` already
+    /// refuses to produce a hiding commitment.
+    fn open<'a>(
+        ck: &Self::CommitterKey,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<'a, G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: G::ScalarField,
+        opening_challenge: G::ScalarField,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+        _rng: Option<&mut dyn RngCore>,
+    ) -> Result<Self::Proof, Self::Error>
+    where
+        Self::Randomness: 'a,
+        Self::Commitment: 'a,
+    {
+        // Combine every polynomial queried at `point` into one, via a random
+        // linear combination with successive powers of `opening_challenge`,
+        // and run the inner-product argument once on the combined
+        // polynomial. The same recombination, applied to `commitments`
+        // instead of the polynomials themselves, is what `check` later
+        // replays to bind the transcript to the statement being proven.
+        let _ = rands;
+        let degree_plus_one = ck.comm_key.len();
+        let mut challenge = G::ScalarField::one();
+        let mut a = vec![G::ScalarField::zero(); degree_plus_one];
+        let mut combined_commitment = G::Projective::zero();
+
+        for (poly, commitment) in labeled_polynomials.into_iter().zip(commitments) {
+            for (c, coeff) in a.iter_mut().zip(&poly.polynomial().coeffs) {
+                *c += &(*coeff * challenge);
+            }
+            combined_commitment += &commitment.commitment().0.mul(challenge);
+            challenge *= &opening_challenge;
+        }
+
+        let mut b = point_powers(point, degree_plus_one);
+        let value = inner_product(&a, &b);
+        let accumulator = combined_commitment + &ck.s.mul(value);
+
+        let mut comm_key = ck.comm_key.clone();
+        let mut transcript = seed_transcript::<G>(point, accumulator);
+
+        let mut l_vec = Vec::with_capacity(degree_plus_one.trailing_zeros() as usize);
+        let mut r_vec = Vec::with_capacity(degree_plus_one.trailing_zeros() as usize);
+        while a.len() > 1 {
+            let round = Self::prover_round(&mut a, &mut b, &mut comm_key, ck.s, &mut transcript);
+            l_vec.push(round.l.into_affine());
+            r_vec.push(round.r.into_affine());
+        }
+
+        Ok(Proof {
+            l_vec,
+            r_vec,
+            final_comm_key: comm_key[0],
+            c: a[0],
+        })
+    }
+
+    fn check<'a>(
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: G::ScalarField,
+        values: impl IntoIterator<Item = G::ScalarField>,
+        proof: &Self::Proof,
+        opening_challenge: G::ScalarField,
+        _rng: Option<&mut dyn RngCore>,
+    ) -> Result<bool, Self::Error>
+    where
+        Self::Commitment: 'a,
+    {
+        // Recombine the commitments and claimed values the same way `open`
+        // recombined the polynomials, bind the result to `s`, and replay the
+        // folding to check it against `proof`.
+        let mut challenge = G::ScalarField::one();
+        let mut combined_commitment = G::Projective::zero();
+        let mut combined_value = G::ScalarField::zero();
+
+        for (commitment, value) in commitments.into_iter().zip(values) {
+            combined_commitment += &commitment.commitment().0.mul(challenge);
+            combined_value += &(value * challenge);
+            challenge *= &opening_challenge;
+        }
+
+        let accumulator = combined_commitment + &vk.s.mul(combined_value);
+        let (folded_generator, folded_b, folded_accumulator) =
+            Self::verifier_fold(vk.comm_key.clone(), point, accumulator, proof)?;
+
+        let expected = folded_generator.mul(proof.c) + &vk.s.mul(proof.c * folded_b);
+        Ok(proof.final_comm_key == folded_generator && folded_accumulator == expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::accumulation::Accumulator;
+    use super::bivariate::BivariateCommitment;
+    use crate::{BivariatePolynomial, Evaluations, Polynomial, QuerySet};
+    use algebra::edwards_bls12::{EdwardsAffine, Fr};
+    use blake2::Blake2s;
+    use rand::thread_rng;
+
+    type PC = InnerProductArgPC<EdwardsAffine, Blake2s>;
+
+    fn rand_poly(degree: usize) -> Polynomial<Fr> {
+        Polynomial::from_coefficients_vec(
+            (0..=degree).map(|_| Fr::rand(&mut thread_rng())).collect(),
+        )
+    }
+
+    #[test]
+    fn commit_open_check_round_trip() {
+        let pp = PC::setup(15, &mut thread_rng()).unwrap();
+        let (ck, vk) = PC::trim(&pp, 15).unwrap();
+
+        let poly = LabeledPolynomial::new_owned("p".to_string(), rand_poly(15), None, None);
+        let (comms, rands) = PC::commit(&ck, [&poly], None).unwrap();
+
+        let point = Fr::rand(&mut thread_rng());
+        let value = poly.evaluate(point);
+        let opening_challenge = Fr::rand(&mut thread_rng());
+
+        let proof = PC::open(&ck, [&poly], &comms, point, opening_challenge, &rands, None).unwrap();
+        assert!(PC::check(&vk, &comms, point, [value], &proof, opening_challenge, None).unwrap());
+
+        // A wrong claimed value must be rejected.
+        let wrong_value = value + Fr::from(1u64);
+        assert!(!PC::check(&vk, &comms, point, [wrong_value], &proof, opening_challenge, None).unwrap());
+    }
+
+    #[test]
+    fn commit_rejects_hiding_polynomials() {
+        let pp = PC::setup(7, &mut thread_rng()).unwrap();
+        let (ck, _vk) = PC::trim(&pp, 7).unwrap();
+
+        let poly = LabeledPolynomial::new_owned("p".to_string(), rand_poly(7), None, Some(1));
+        assert!(PC::commit(&ck, [&poly], Some(&mut thread_rng())).is_err());
+    }
+
+    #[test]
+    fn accumulator_round_trip() {
+        let pp = PC::setup(15, &mut thread_rng()).unwrap();
+        let (ck, vk) = PC::trim(&pp, 15).unwrap();
+
+        let mut accumulator = Accumulator::<EdwardsAffine>::new();
+        for _ in 0..3 {
+            let poly = LabeledPolynomial::new_owned("p".to_string(), rand_poly(15), None, None);
+            let (comms, rands) = PC::commit(&ck, [&poly], None).unwrap();
+            let point = Fr::rand(&mut thread_rng());
+            let value = poly.evaluate(point);
+            let opening_challenge = Fr::rand(&mut thread_rng());
+            let proof =
+                PC::open(&ck, [&poly], &comms, point, opening_challenge, &rands, None).unwrap();
+
+            accumulator
+                .accumulate::<Blake2s>(&vk, comms[0].commitment(), point, value, &proof)
+                .unwrap();
+        }
+
+        assert!(accumulator.verify::<Blake2s>());
+    }
+
+    #[test]
+    fn batch_open_batch_check_round_trip() {
+        let pp = PC::setup(15, &mut thread_rng()).unwrap();
+        let (ck, vk) = PC::trim(&pp, 15).unwrap();
+
+        let poly_a = LabeledPolynomial::new_owned("a".to_string(), rand_poly(7), None, None);
+        let poly_b = LabeledPolynomial::new_owned("b".to_string(), rand_poly(15), None, None);
+        let (comms, rands) = PC::commit(&ck, [&poly_a, &poly_b], None).unwrap();
+
+        let point = Fr::rand(&mut thread_rng());
+        let mut query_set = QuerySet::new();
+        query_set.insert(("a".to_string(), ("z".to_string(), point)));
+        query_set.insert(("b".to_string(), ("z".to_string(), point)));
+
+        let mut evaluations = Evaluations::new();
+        evaluations.insert(("a".to_string(), point), poly_a.evaluate(point));
+        evaluations.insert(("b".to_string(), point), poly_b.evaluate(point));
+
+        let opening_challenge = Fr::rand(&mut thread_rng());
+        let batch_proof = PC::batch_open(
+            &ck,
+            [&poly_a, &poly_b],
+            &comms,
+            &query_set,
+            opening_challenge,
+            &rands,
+            None,
+        )
+        .unwrap();
+
+        assert!(PC::batch_check(
+            &vk,
+            &comms,
+            &query_set,
+            &evaluations,
+            &batch_proof,
+            opening_challenge,
+            &mut thread_rng(),
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn bivariate_verify_share() {
+        let pp = PC::setup(15, &mut thread_rng()).unwrap();
+        let (ck, _vk) = PC::trim(&pp, 15).unwrap();
+
+        let row_polynomials = vec![rand_poly(3), rand_poly(3), rand_poly(3)];
+        let bivariate = BivariatePolynomial::from_row_polynomials(row_polynomials);
+        let commitment = BivariateCommitment::commit(&ck.comm_key, &bivariate).unwrap();
+
+        let x = Fr::rand(&mut thread_rng());
+        let share = bivariate.row_at(x);
+        assert!(commitment.verify_share(&ck.comm_key, x, &share).unwrap());
+
+        // A share for a different point should not verify.
+        let other_x = x + Fr::from(1u64);
+        let other_share = bivariate.row_at(other_x);
+        assert!(!commitment.verify_share(&ck.comm_key, x, &other_share).unwrap());
+    }
+}
@@ -0,0 +1,280 @@
+//! A crate for polynomial commitment schemes.
+use algebra::{CanonicalDeserialize, CanonicalSerialize, Field};
+use rand_core::RngCore;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub mod data_structures;
+pub use data_structures::*;
+
+mod error;
+pub use error::*;
+
+pub mod ipa_pc;
+
+/// A set of queries, each of which is a `(polynomial_label, (point_label,
+/// point))` triple. Points are identified by a label rather than compared
+/// directly so that polynomials queried "at the same point" can be grouped
+/// by that label even when the point itself is produced independently (e.g.
+/// derived from a transcript) in several places.
+pub type QuerySet<F> = BTreeSet<(String, (String, F))>;
+
+/// The claimed evaluation of each `(polynomial_label, point)` pair appearing
+/// in a `QuerySet`.
+pub type Evaluations<F> = BTreeMap<(String, F), F>;
+
+/// Returns `Err(Error::Terminated)` if `terminator` has been set, and `Ok(())`
+/// otherwise. Scheme implementations of `commit_with_terminator` should call
+/// this between polynomials and between MSM chunks so that the caller's
+/// abort request is honored promptly rather than only at the end of the call.
+#[inline]
+pub fn check_terminator<E: From<Error>>(terminator: &AtomicBool) -> Result<(), E> {
+    if terminator.load(Ordering::Relaxed) {
+        Err(Error::Terminated.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Describes the interface for a polynomial commitment scheme.
+pub trait PolynomialCommitment<F: Field>: Sized {
+    /// The universal parameters for the scheme.
+    type UniversalParams: PCUniversalParams;
+    /// The committer key for the scheme.
+    type CommitterKey: PCCommitterKey;
+    /// The verifier key for the scheme.
+    type VerifierKey: PCVerifierKey;
+    /// The commitment type produced by the scheme.
+    type Commitment: PCCommitment;
+    /// The randomness type used to hide a commitment.
+    type Randomness: PCRandomness;
+    /// The evaluation proof type produced by the scheme.
+    type Proof: PCProof;
+    /// A proof of evaluation for several polynomials at several points,
+    /// amortized into one proof per distinct point. Any scheme's `Proof` can
+    /// be used here unchanged, since a batch proof is just the list of
+    /// per-point proofs produced by `batch_open`.
+    type BatchProof: Clone
+        + CanonicalSerialize
+        + CanonicalDeserialize
+        + From<Vec<Self::Proof>>
+        + Into<Vec<Self::Proof>>;
+    /// The error type returned by the scheme's operations.
+    type Error: std::error::Error + From<Error>;
+
+    /// Constructs public parameters given a maximum degree `max_degree`
+    /// for the polynomials the scheme needs to commit to.
+    fn setup<R: RngCore>(
+        max_degree: usize,
+        rng: &mut R,
+    ) -> Result<Self::UniversalParams, Self::Error>;
+
+    /// Specializes the public parameters for polynomials up to the
+    /// supplied `supported_degree` into committer and verifier keys.
+    fn trim(
+        pp: &Self::UniversalParams,
+        supported_degree: usize,
+    ) -> Result<(Self::CommitterKey, Self::VerifierKey), Self::Error>;
+
+    /// Outputs a commitment to each polynomial in `polynomials`, along with
+    /// the randomness used to produce each commitment.
+    ///
+    /// This is a thin wrapper around [`commit_with_terminator`] that never
+    /// aborts.
+    ///
+    /// [`commit_with_terminator`]: Self::commit_with_terminator
+    fn commit<'a>(
+        ck: &Self::CommitterKey,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<'a, F>>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<
+        (
+            Vec<LabeledCommitment<Self::Commitment>>,
+            Vec<Self::Randomness>,
+        ),
+        Self::Error,
+    > {
+        Self::commit_with_terminator(ck, polynomials, &AtomicBool::new(false), rng)
+    }
+
+    /// Outputs a commitment to each polynomial in `polynomials`, periodically
+    /// checking `terminator` (between polynomials, and between MSM chunks
+    /// within a single commitment) and returning `Error::Terminated` as soon
+    /// as it is set. This lets a caller abort a commitment to hundreds of
+    /// large polynomials — e.g. for an indexer — instead of having to wait
+    /// for it to run to completion.
+    fn commit_with_terminator<'a>(
+        ck: &Self::CommitterKey,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<'a, F>>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<
+        (
+            Vec<LabeledCommitment<Self::Commitment>>,
+            Vec<Self::Randomness>,
+        ),
+        Self::Error,
+    >;
+
+    /// On input a list of labeled polynomials and a query point, outputs a
+    /// proof of evaluation of the polynomials at the query point.
+    fn open<'a>(
+        ck: &Self::CommitterKey,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<'a, F>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: F,
+        opening_challenge: F,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<Self::Proof, Self::Error>
+    where
+        Self::Randomness: 'a,
+        Self::Commitment: 'a;
+
+    /// Verifies that `values` are the true evaluations at `point` of the
+    /// polynomials committed to in `commitments`.
+    fn check<'a>(
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        point: F,
+        values: impl IntoIterator<Item = F>,
+        proof: &Self::Proof,
+        opening_challenge: F,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<bool, Self::Error>
+    where
+        Self::Commitment: 'a;
+
+    /// Opens every polynomial named in `query_set` at its queried point,
+    /// grouping by point label so that all polynomials sharing a point are
+    /// opened together (and hence amortized into a single `Proof` by
+    /// `open`'s own random-linear-combination machinery). Returns one proof
+    /// per distinct point, in point-label order.
+    fn batch_open<'a>(
+        ck: &Self::CommitterKey,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<'a, F>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        query_set: &QuerySet<F>,
+        opening_challenge: F,
+        rands: impl IntoIterator<Item = &'a Self::Randomness>,
+        mut rng: Option<&mut dyn RngCore>,
+    ) -> Result<Self::BatchProof, Self::Error>
+    where
+        Self::Randomness: 'a,
+        Self::Commitment: 'a,
+        F: Ord,
+    {
+        let polys: Vec<_> = labeled_polynomials.into_iter().collect();
+        let comms: Vec<_> = commitments.into_iter().collect();
+        let rands: Vec<_> = rands.into_iter().collect();
+
+        // Group the queries sharing a point label together, regardless of
+        // the order `query_set` happens to store them in.
+        let mut points_by_label: BTreeMap<&str, F> = BTreeMap::new();
+        let mut labels_by_point: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for (poly_label, (point_label, point)) in query_set {
+            points_by_label.insert(point_label, *point);
+            labels_by_point
+                .entry(point_label)
+                .or_insert_with(BTreeSet::new)
+                .insert(poly_label);
+        }
+
+        let mut proofs = Vec::with_capacity(labels_by_point.len());
+        for (point_label, poly_labels) in &labels_by_point {
+            let point = points_by_label[point_label];
+            let polys_at_point = polys.iter().copied().filter(|p| poly_labels.contains(p.label()));
+            let comms_at_point = comms.iter().copied().filter(|c| poly_labels.contains(c.label()));
+            let rands_at_point = polys
+                .iter()
+                .zip(&rands)
+                .filter(|(p, _)| poly_labels.contains(p.label()))
+                .map(|(_, r)| *r);
+
+            let proof = Self::open(
+                ck,
+                polys_at_point,
+                comms_at_point,
+                point,
+                opening_challenge,
+                rands_at_point,
+                rng.as_deref_mut(),
+            )?;
+            proofs.push(proof);
+        }
+
+        Ok(proofs.into())
+    }
+
+    /// Verifies a `BatchProof` produced by `batch_open` against `query_set`
+    /// and the claimed `evaluations`, grouping by point label the same way
+    /// `batch_open` did.
+    fn batch_check<'a, R: RngCore>(
+        vk: &Self::VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Self::Commitment>>,
+        query_set: &QuerySet<F>,
+        evaluations: &Evaluations<F>,
+        proof: &Self::BatchProof,
+        opening_challenge: F,
+        rng: &mut R,
+    ) -> Result<bool, Self::Error>
+    where
+        Self::Commitment: 'a,
+        F: Ord,
+    {
+        let comms: Vec<_> = commitments.into_iter().collect();
+
+        let mut points_by_label: BTreeMap<&str, F> = BTreeMap::new();
+        let mut labels_by_point: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for (poly_label, (point_label, point)) in query_set {
+            points_by_label.insert(point_label, *point);
+            labels_by_point
+                .entry(point_label)
+                .or_insert_with(BTreeSet::new)
+                .insert(poly_label);
+        }
+
+        let proofs: Vec<Self::Proof> = proof.clone().into();
+        if proofs.len() != labels_by_point.len() {
+            return Err(Error::IncorrectInputLength(format!(
+                "expected {} per-point proofs, got {}",
+                labels_by_point.len(),
+                proofs.len()
+            ))
+            .into());
+        }
+
+        for ((point_label, poly_labels), proof) in labels_by_point.iter().zip(&proofs) {
+            let point = points_by_label[point_label];
+            let comms_at_point: Vec<_> = comms
+                .iter()
+                .copied()
+                .filter(|c| poly_labels.contains(c.label()))
+                .collect();
+
+            let mut values = Vec::with_capacity(comms_at_point.len());
+            for comm in &comms_at_point {
+                let key = (comm.label().to_string(), point);
+                let value = evaluations.get(&key).ok_or_else(|| Error::MissingEvaluation {
+                    label: comm.label().to_string(),
+                })?;
+                values.push(*value);
+            }
+
+            let result = Self::check(
+                vk,
+                comms_at_point,
+                point,
+                values,
+                proof,
+                opening_challenge,
+                Some(&mut *rng),
+            )?;
+            if !result {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}